@@ -10,15 +10,27 @@ pub fn run() {
     .invoke_handler(tauri::generate_handler![
       ollama::get_hardware_info,
       ollama::check_ollama_status,
+      ollama::get_ollama_settings,
+      ollama::save_ollama_settings,
       ollama::start_ollama,
       ollama::download_model,
       ollama::query_ollama,
+      ollama::query_ollama_stream,
+      ollama::chat_ollama,
+      ollama::generate_embeddings,
+      ollama::get_embedding_dimension,
+      ollama::set_ollama_rate_limit,
+      ollama::preload_model,
       ollama::list_installed_models,
       ollama::get_model_recommendations,
       ollama::setup_bundled_ollama,
       python_manager::check_python_status,
       python_manager::setup_embedded_python,
-      python_manager::get_python_path
+      python_manager::get_python_path,
+      python_manager::regenerate_medical_lockfile,
+      python_manager::list_installed_pythons,
+      python_manager::install_python,
+      python_manager::select_python
     ])
     .setup(|app| {
       if cfg!(debug_assertions) {
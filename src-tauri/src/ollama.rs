@@ -1,12 +1,16 @@
+use futures_util::StreamExt;
 use reqwest;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
 use sysinfo::System;
 use tauri::command;
+use tauri::ipc::Channel;
 use tauri::{AppHandle, Manager};
-use tokio::time::{timeout, Duration};
+use tokio::time::{timeout, Duration, Instant};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HardwareInfo {
@@ -17,6 +21,9 @@ pub struct HardwareInfo {
     pub can_run_7b: bool,
     pub can_run_mini: bool,
     pub os: String,
+    pub gpu_vendor: Option<String>, // "Nvidia", "AMD", "Apple"
+    pub gpu_memory_gb: Option<f64>,
+    pub acceleration: String, // "cuda", "rocm", "metal", "cpu"
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,6 +33,7 @@ pub struct ModelInfo {
     pub description: String,
     pub recommended_ram_gb: f64,
     pub is_medical: bool,
+    pub is_embedding: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -42,7 +50,221 @@ pub struct QueryRequest {
     pub stream: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ChatOptions {
+    pub num_ctx: Option<u32>,
+    pub temperature: Option<f32>,
+    pub seed: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatApiResponse {
+    message: ChatMessage,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatReply {
+    pub reply: ChatMessage,
+    pub messages: Vec<ChatMessage>,
+}
+
 const OLLAMA_BASE_URL: &str = "http://localhost:11434";
+const DEFAULT_MAX_REQUESTS_PER_SECOND: f64 = 5.0;
+const DEFAULT_MAX_RETRY_ATTEMPTS: u32 = 4;
+
+/// Client-side request governor shared by query/chat/embedding calls, so a
+/// burst of UI-driven requests can't overwhelm a model that's still loading.
+struct RequestGovernor {
+    min_interval: Mutex<Duration>,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl RequestGovernor {
+    fn new(max_requests_per_second: f64) -> Self {
+        Self {
+            min_interval: Mutex::new(Duration::from_secs_f64(1.0 / max_requests_per_second.max(0.01))),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    fn set_rate(&self, max_requests_per_second: f64) {
+        *self.min_interval.lock().unwrap() =
+            Duration::from_secs_f64(1.0 / max_requests_per_second.max(0.01));
+    }
+
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let min_interval = *self.min_interval.lock().unwrap();
+                let mut last = self.last_request.lock().unwrap();
+                let now = Instant::now();
+                match *last {
+                    Some(prev) if now.duration_since(prev) < min_interval => {
+                        Some(min_interval - now.duration_since(prev))
+                    }
+                    _ => {
+                        *last = Some(now);
+                        None
+                    }
+                }
+            };
+
+            match wait {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => return,
+            }
+        }
+    }
+}
+
+fn governor() -> &'static RequestGovernor {
+    static GOVERNOR: OnceLock<RequestGovernor> = OnceLock::new();
+    GOVERNOR.get_or_init(|| RequestGovernor::new(DEFAULT_MAX_REQUESTS_PER_SECOND))
+}
+
+#[command]
+pub fn set_ollama_rate_limit(max_requests_per_second: f64) {
+    governor().set_rate(max_requests_per_second);
+}
+
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    // 429 = rate limited, 503 = model still loading into memory.
+    matches!(status.as_u16(), 429 | 503)
+}
+
+async fn backoff_sleep(attempt: u32) {
+    let millis = 200u64.saturating_mul(1u64 << attempt.min(5));
+    tokio::time::sleep(Duration::from_millis(millis)).await;
+}
+
+/// Sends a request through the shared governor, retrying transient failures
+/// (connection errors, 429, 503) with exponential backoff. A response that
+/// comes back with a user-fault status (e.g. 404 model not found) is handed
+/// to the caller unretried so it can surface a clearly-attributed error.
+async fn send_governed(
+    build: impl Fn() -> reqwest::RequestBuilder,
+    max_attempts: u32,
+) -> Result<reqwest::Response, String> {
+    let mut attempt = 0;
+
+    loop {
+        governor().acquire().await;
+
+        match build().send().await {
+            Ok(response) => {
+                if response.status().is_success() || !is_retryable_status(response.status()) {
+                    return Ok(response);
+                }
+
+                attempt += 1;
+                if attempt >= max_attempts {
+                    return Ok(response);
+                }
+                log::warn!(
+                    "Ollama request got {}, retrying ({}/{})",
+                    response.status(),
+                    attempt,
+                    max_attempts
+                );
+                backoff_sleep(attempt).await;
+            }
+            Err(e) => {
+                attempt += 1;
+                if attempt >= max_attempts {
+                    return Err(format!("Network error after {} attempts: {}", attempt, e));
+                }
+                log::warn!(
+                    "Ollama request failed, retrying ({}/{}): {}",
+                    attempt,
+                    max_attempts,
+                    e
+                );
+                backoff_sleep(attempt).await;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaSettings {
+    pub api_url: String,
+    pub api_key: Option<String>,
+}
+
+impl Default for OllamaSettings {
+    fn default() -> Self {
+        Self {
+            api_url: OLLAMA_BASE_URL.to_string(),
+            api_key: None,
+        }
+    }
+}
+
+fn settings_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let config_dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to get app config directory: {}", e))?;
+    Ok(config_dir.join("ollama_settings.json"))
+}
+
+/// Loads persisted Ollama settings, falling back to `OLLAMA_API_KEY` when no
+/// key has been saved explicitly.
+fn load_settings(app_handle: &AppHandle) -> OllamaSettings {
+    let mut settings: OllamaSettings = settings_path(app_handle)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+
+    if settings.api_key.is_none() {
+        if let Ok(env_key) = std::env::var("OLLAMA_API_KEY") {
+            settings.api_key = Some(env_key);
+        }
+    }
+
+    settings
+}
+
+#[command]
+pub async fn get_ollama_settings(app_handle: AppHandle) -> Result<OllamaSettings, String> {
+    Ok(load_settings(&app_handle))
+}
+
+#[command]
+pub async fn save_ollama_settings(app_handle: AppHandle, settings: OllamaSettings) -> Result<(), String> {
+    let path = settings_path(&app_handle)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+
+    let contents = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write settings: {}", e))
+}
+
+fn authorize(builder: reqwest::RequestBuilder, settings: &OllamaSettings) -> reqwest::RequestBuilder {
+    match &settings.api_key {
+        Some(token) => builder.bearer_auth(token),
+        None => builder,
+    }
+}
 
 pub fn get_available_models() -> Vec<ModelInfo> {
     vec![
@@ -52,6 +274,7 @@ pub fn get_available_models() -> Vec<ModelInfo> {
             description: "TinyLlama 1.1B - Fast and lightweight model for basic analysis".to_string(),
             recommended_ram_gb: 4.0,
             is_medical: false,
+            is_embedding: false,
         },
         ModelInfo {
             name: "phi3:mini".to_string(),
@@ -59,6 +282,7 @@ pub fn get_available_models() -> Vec<ModelInfo> {
             description: "Phi-3 Mini - Balanced performance for general analysis".to_string(),
             recommended_ram_gb: 6.0,
             is_medical: false,
+            is_embedding: false,
         },
         ModelInfo {
             name: "biomistral:7b".to_string(),
@@ -66,6 +290,15 @@ pub fn get_available_models() -> Vec<ModelInfo> {
             description: "BioMistral 7B - Specialized medical research model".to_string(),
             recommended_ram_gb: 8.0,
             is_medical: true,
+            is_embedding: false,
+        },
+        ModelInfo {
+            name: "nomic-embed-text".to_string(),
+            size_gb: 0.3,
+            description: "Nomic Embed Text - Embedding model for semantic search and RAG".to_string(),
+            recommended_ram_gb: 2.0,
+            is_medical: false,
+            is_embedding: true,
         },
     ]
 }
@@ -85,6 +318,85 @@ fn get_bundled_ollama_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
     Ok(resource_dir.join("ollama").join(ollama_binary))
 }
 
+/// Vendor, VRAM (GB), and the accelerator backend Ollama would pick for it.
+struct GpuInfo {
+    vendor: Option<String>,
+    memory_gb: Option<f64>,
+    acceleration: String,
+}
+
+/// Pulls the total VRAM in bytes out of `rocm-smi --showmeminfo vram --json`
+/// output. Each card is keyed by name (e.g. `"card0"`); we only need the
+/// first one that reports a total, since `has_strong_gpu` just cares whether
+/// *a* GPU clears the threshold.
+fn parse_rocm_vram_bytes(json: &[u8]) -> Option<f64> {
+    let data: serde_json::Value = serde_json::from_slice(json).ok()?;
+    data.as_object()?.values().find_map(|card| {
+        card.get("VRAM Total Memory (B)")?
+            .as_str()?
+            .trim()
+            .parse::<f64>()
+            .ok()
+    })
+}
+
+fn detect_gpu() -> GpuInfo {
+    // Nvidia: query the driver directly via nvidia-smi, same as Ollama does.
+    if let Ok(output) = Command::new("nvidia-smi")
+        .args(&["--query-gpu=memory.total", "--format=csv,noheader,nounits"])
+        .output()
+    {
+        if output.status.success() {
+            if let Some(memory_mb) = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .next()
+                .and_then(|line| line.trim().parse::<f64>().ok())
+            {
+                return GpuInfo {
+                    vendor: Some("Nvidia".to_string()),
+                    memory_gb: Some(memory_mb / 1024.0),
+                    acceleration: "cuda".to_string(),
+                };
+            }
+        }
+    }
+
+    // AMD: rocm-smi reports VRAM per-GPU in bytes. Ask for --json rather than
+    // --csv: the CSV header order isn't guaranteed across rocm-smi versions,
+    // and "Total Used Memory" sorts next to "Total Memory" in that output,
+    // so grabbing a column by position silently reads the wrong field.
+    if let Ok(output) = Command::new("rocm-smi")
+        .args(&["--showmeminfo", "vram", "--json"])
+        .output()
+    {
+        if output.status.success() {
+            if let Some(memory_bytes) = parse_rocm_vram_bytes(&output.stdout) {
+                return GpuInfo {
+                    vendor: Some("AMD".to_string()),
+                    memory_gb: Some(memory_bytes / (1024.0 * 1024.0 * 1024.0)),
+                    acceleration: "rocm".to_string(),
+                };
+            }
+        }
+    }
+
+    // Apple Silicon shares system RAM with the GPU through the unified memory
+    // architecture, so there's no separate VRAM figure to query.
+    if cfg!(target_os = "macos") && std::env::consts::ARCH == "aarch64" {
+        return GpuInfo {
+            vendor: Some("Apple".to_string()),
+            memory_gb: None,
+            acceleration: "metal".to_string(),
+        };
+    }
+
+    GpuInfo {
+        vendor: None,
+        memory_gb: None,
+        acceleration: "cpu".to_string(),
+    }
+}
+
 #[command]
 pub async fn get_hardware_info() -> Result<HardwareInfo, String> {
     let mut sys = System::new_all();
@@ -94,18 +406,23 @@ pub async fn get_hardware_info() -> Result<HardwareInfo, String> {
     let available_memory = sys.available_memory() as f64 / (1024.0 * 1024.0 * 1024.0);
     let cpu_count = sys.cpus().len();
 
-    // Determine recommended model based on available RAM
-    let recommended_model = if total_memory >= 8.0 {
+    let gpu = detect_gpu();
+    let has_strong_gpu = gpu.memory_gb.unwrap_or(0.0) >= 8.0
+        && matches!(gpu.acceleration.as_str(), "cuda" | "rocm");
+
+    // Determine recommended model based on available RAM, boosted by a
+    // capable accelerator that can carry the weights the CPU/RAM can't.
+    let can_run_7b = total_memory >= 8.0 || (total_memory >= 6.0 && has_strong_gpu);
+    let can_run_mini = total_memory >= 6.0 || has_strong_gpu;
+
+    let recommended_model = if can_run_7b {
         "biomistral:7b".to_string()
-    } else if total_memory >= 6.0 {
+    } else if can_run_mini {
         "phi3:mini".to_string()
     } else {
         "tinyllama".to_string()
     };
 
-    let can_run_7b = total_memory >= 8.0;
-    let can_run_mini = total_memory >= 6.0;
-
     let os = if cfg!(target_os = "windows") {
         "Windows".to_string()
     } else if cfg!(target_os = "macos") {
@@ -124,14 +441,23 @@ pub async fn get_hardware_info() -> Result<HardwareInfo, String> {
         can_run_7b,
         can_run_mini,
         os,
+        gpu_vendor: gpu.vendor,
+        gpu_memory_gb: gpu.memory_gb,
+        acceleration: gpu.acceleration,
     })
 }
 
 #[command]
-pub async fn check_ollama_status() -> Result<bool, String> {
+pub async fn check_ollama_status(app_handle: AppHandle) -> Result<bool, String> {
     let client = reqwest::Client::new();
-    
-    match timeout(Duration::from_secs(5), client.get(&format!("{}/api/tags", OLLAMA_BASE_URL)).send()).await {
+    let settings = load_settings(&app_handle);
+
+    let request = authorize(
+        client.get(&format!("{}/api/tags", settings.api_url)),
+        &settings,
+    );
+
+    match timeout(Duration::from_secs(5), request.send()).await {
         Ok(Ok(response)) => Ok(response.status().is_success()),
         Ok(Err(e)) => {
             log::warn!("Ollama check failed: {}", e);
@@ -147,7 +473,7 @@ pub async fn check_ollama_status() -> Result<bool, String> {
 #[command]
 pub async fn start_ollama(app_handle: AppHandle) -> Result<String, String> {
     // Check if already running
-    if check_ollama_status().await.unwrap_or(false) {
+    if check_ollama_status(app_handle.clone()).await.unwrap_or(false) {
         return Ok("Ollama is already running".to_string());
     }
 
@@ -176,7 +502,7 @@ pub async fn start_ollama(app_handle: AppHandle) -> Result<String, String> {
                         tokio::time::sleep(Duration::from_secs(3)).await;
                         
                         // Verify it started
-                        if check_ollama_status().await.unwrap_or(false) {
+                        if check_ollama_status(app_handle.clone()).await.unwrap_or(false) {
                             return Ok("Ollama started successfully".to_string());
                         } else {
                             last_error = "Ollama process started but service is not responding".to_string();
@@ -198,46 +524,102 @@ pub async fn start_ollama(app_handle: AppHandle) -> Result<String, String> {
     Err(format!("Failed to start Ollama. Last error: {}", last_error))
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadProgress {
+    pub status: String,
+    pub total: Option<u64>,
+    pub completed: Option<u64>,
+    pub percent: Option<f64>,
+}
+
 #[command]
-pub async fn download_model(model_name: String, app_handle: AppHandle) -> Result<String, String> {
+pub async fn download_model(
+    app_handle: AppHandle,
+    model_name: String,
+    channel: Channel<DownloadProgress>,
+) -> Result<String, String> {
     log::info!("Starting download for model: {}", model_name);
-    
-    // Try bundled Ollama first, then system Ollama
-    let ollama_commands = vec![
-        get_bundled_ollama_path(&app_handle),
-        Ok(PathBuf::from("ollama")),
-    ];
 
-    for ollama_path in ollama_commands {
-        if let Ok(path) = ollama_path {
-            let result = Command::new(&path)
-                .arg("pull")
-                .arg(&model_name)
-                .output();
-
-            match result {
-                Ok(output) => {
-                    if output.status.success() {
-                        return Ok(format!("Model {} downloaded successfully", model_name));
-                    } else {
-                        let error = String::from_utf8_lossy(&output.stderr);
-                        log::warn!("Download failed with {:?}: {}", path, error);
-                    }
-                },
-                Err(e) => {
-                    log::warn!("Failed to execute ollama pull with {:?}: {}", path, e);
+    let client = reqwest::Client::new();
+    let settings = load_settings(&app_handle);
+
+    let request_body = json!({
+        "name": model_name,
+        "stream": true
+    });
+
+    let request = authorize(
+        client.post(&format!("{}/api/pull", settings.api_url)).json(&request_body),
+        &settings,
+    );
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Ollama at {}: {}", settings.api_url, e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("Ollama API error {}: {}", status, error_text));
+    }
+
+    // Pull status lines are newline-delimited JSON, same shape as generate/chat streaming.
+    let mut stream = response.bytes_stream();
+    let mut buffer = String::new();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Stream error: {}", e))?;
+        buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+        while let Some(newline_pos) = buffer.find('\n') {
+            let line = buffer[..newline_pos].trim().to_string();
+            buffer.drain(..=newline_pos);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let status_line: serde_json::Value = serde_json::from_str(&line)
+                .map_err(|e| format!("Failed to parse pull status: {}", e))?;
+
+            if let Some(error) = status_line["error"].as_str() {
+                return Err(format!("Failed to download model {}: {}", model_name, error));
+            }
+
+            let status = status_line["status"].as_str().unwrap_or("").to_string();
+            let total = status_line["total"].as_u64();
+            let completed = status_line["completed"].as_u64();
+            let percent = match (total, completed) {
+                (Some(total), Some(completed)) if total > 0 => {
+                    Some(completed as f64 / total as f64 * 100.0)
                 }
+                _ => None,
+            };
+
+            channel
+                .send(DownloadProgress {
+                    status: status.clone(),
+                    total,
+                    completed,
+                    percent,
+                })
+                .map_err(|e| format!("Failed to emit download progress: {}", e))?;
+
+            if status == "success" {
+                return Ok(format!("Model {} downloaded successfully", model_name));
             }
         }
     }
-    
-    Err(format!("Failed to download model {}. Please ensure Ollama is running.", model_name))
+
+    Ok(format!("Model {} downloaded successfully", model_name))
 }
 
 #[command]
-pub async fn query_ollama(model: String, prompt: String) -> Result<String, String> {
+pub async fn query_ollama(app_handle: AppHandle, model: String, prompt: String) -> Result<String, String> {
     let client = reqwest::Client::new();
-    
+    let settings = load_settings(&app_handle);
+
     let request_body = json!({
         "model": model,
         "prompt": prompt,
@@ -246,12 +628,19 @@ pub async fn query_ollama(model: String, prompt: String) -> Result<String, Strin
 
     log::info!("Querying Ollama with model: {} and prompt length: {}", model, prompt.len());
 
+    let build_request = || {
+        authorize(
+            client.post(&format!("{}/api/generate", settings.api_url)).json(&request_body),
+            &settings,
+        )
+    };
+
     match timeout(
-        Duration::from_secs(30), 
-        client.post(&format!("{}/api/generate", OLLAMA_BASE_URL))
-            .json(&request_body)
-            .send()
-    ).await {
+        Duration::from_secs(30),
+        send_governed(build_request, DEFAULT_MAX_RETRY_ATTEMPTS),
+    )
+    .await
+    {
         Ok(Ok(response)) => {
             if response.status().is_success() {
                 match response.json::<OllamaResponse>().await {
@@ -261,22 +650,289 @@ pub async fn query_ollama(model: String, prompt: String) -> Result<String, Strin
             } else {
                 let status = response.status();
                 let error_text = response.text().await.unwrap_or_default();
-                Err(format!("Ollama API error {}: {}", status, error_text))
+                if status.as_u16() == 404 {
+                    Err(format!("Model '{}' not found. Pull it before querying.", model))
+                } else {
+                    Err(format!("Ollama API error {}: {}", status, error_text))
+                }
             }
         },
-        Ok(Err(e)) => Err(format!("Network error: {}", e)),
+        Ok(Err(e)) => Err(e),
         Err(_) => Err("Query timeout (30s)".to_string()),
     }
 }
 
+/// Per-model embedding dimension, learned the first time a model is used.
+fn embedding_dimensions() -> &'static Mutex<HashMap<String, usize>> {
+    static DIMENSIONS: OnceLock<Mutex<HashMap<String, usize>>> = OnceLock::new();
+    DIMENSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Returns the vector size `generate_embeddings` learned for `model`, or
+/// `None` if that model hasn't been probed yet (i.e. no embeddings have
+/// been generated with it in this session).
+#[command]
+pub async fn get_embedding_dimension(model: String) -> Option<usize> {
+    embedding_dimensions().lock().unwrap().get(&model).copied()
+}
+
+async fn request_embedding(
+    client: &reqwest::Client,
+    settings: &OllamaSettings,
+    model: &str,
+    text: &str,
+) -> Result<Vec<f32>, String> {
+    let request_body = json!({
+        "model": model,
+        "prompt": text,
+    });
+
+    let build_request = || {
+        authorize(
+            client.post(&format!("{}/api/embeddings", settings.api_url)).json(&request_body),
+            settings,
+        )
+    };
+    let response = send_governed(build_request, DEFAULT_MAX_RETRY_ATTEMPTS).await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        if status.as_u16() == 404 {
+            return Err(format!(
+                "Embedding model '{}' is not installed. Pull it with `ollama pull {}` before generating embeddings.",
+                model, model
+            ));
+        }
+        return Err(format!("Ollama API error {}: {}", status, error_text));
+    }
+
+    let data: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse embedding response: {}", e))?;
+
+    data["embedding"]
+        .as_array()
+        .map(|values| values.iter().filter_map(|v| v.as_f64()).map(|v| v as f32).collect())
+        .ok_or_else(|| "Embedding response missing `embedding` array".to_string())
+}
+
 #[command]
-pub async fn list_installed_models() -> Result<Vec<String>, String> {
+pub async fn generate_embeddings(
+    app_handle: AppHandle,
+    model: String,
+    texts: Vec<String>,
+) -> Result<Vec<Vec<f32>>, String> {
     let client = reqwest::Client::new();
-    
+    let settings = load_settings(&app_handle);
+
+    // Learn (and cache) this model's vector size from a throwaway probe the
+    // first time it's used, so callers never have to hardcode dimensions.
+    if !embedding_dimensions().lock().unwrap().contains_key(&model) {
+        let probe = request_embedding(&client, &settings, &model, "test").await?;
+        embedding_dimensions()
+            .lock()
+            .unwrap()
+            .insert(model.clone(), probe.len());
+    }
+
+    let mut embeddings = Vec::with_capacity(texts.len());
+    for text in &texts {
+        embeddings.push(request_embedding(&client, &settings, &model, text).await?);
+    }
+
+    Ok(embeddings)
+}
+
+#[command]
+pub async fn chat_ollama(
+    app_handle: AppHandle,
+    model: String,
+    mut messages: Vec<ChatMessage>,
+    options: Option<ChatOptions>,
+) -> Result<ChatReply, String> {
+    let client = reqwest::Client::new();
+    let settings = load_settings(&app_handle);
+
+    let options = options.unwrap_or_default();
+    let ollama_options = json!({
+        "num_ctx": options.num_ctx.unwrap_or(4096),
+        "temperature": options.temperature,
+        "seed": options.seed,
+    });
+
+    let request_body = ChatRequest {
+        model: model.clone(),
+        messages: messages.clone(),
+        stream: false,
+        options: Some(ollama_options),
+    };
+
+    log::info!(
+        "Chatting with Ollama model: {} over {} messages",
+        model,
+        messages.len()
+    );
+
+    let build_request = || {
+        authorize(
+            client.post(&format!("{}/api/chat", settings.api_url)).json(&request_body),
+            &settings,
+        )
+    };
+
     match timeout(
-        Duration::from_secs(10),
-        client.get(&format!("{}/api/tags", OLLAMA_BASE_URL)).send()
-    ).await {
+        Duration::from_secs(30),
+        send_governed(build_request, DEFAULT_MAX_RETRY_ATTEMPTS),
+    )
+    .await
+    {
+        Ok(Ok(response)) => {
+            if response.status().is_success() {
+                let chat_response: ChatApiResponse = response
+                    .json()
+                    .await
+                    .map_err(|e| format!("Failed to parse Ollama response: {}", e))?;
+
+                messages.push(chat_response.message.clone());
+
+                Ok(ChatReply {
+                    reply: chat_response.message,
+                    messages,
+                })
+            } else {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_default();
+                Err(format!("Ollama API error {}: {}", status, error_text))
+            }
+        }
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err("Chat request timeout (30s)".to_string()),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreloadResult {
+    pub model: String,
+    pub load_time_ms: u128,
+}
+
+#[command]
+pub async fn preload_model(app_handle: AppHandle, model: String) -> Result<PreloadResult, String> {
+    let client = reqwest::Client::new();
+    let settings = load_settings(&app_handle);
+
+    // An empty prompt with keep_alive set forces Ollama to load the model's
+    // weights without generating anything, so the real query that follows
+    // returns instantly instead of paying the cold-start cost.
+    let request_body = json!({
+        "model": model,
+        "prompt": "",
+        "stream": false,
+        "keep_alive": "5m"
+    });
+
+    let build_request = || {
+        authorize(
+            client.post(&format!("{}/api/generate", settings.api_url)).json(&request_body),
+            &settings,
+        )
+    };
+
+    log::info!("Preloading Ollama model: {}", model);
+
+    let start = Instant::now();
+    let response = send_governed(build_request, DEFAULT_MAX_RETRY_ATTEMPTS).await?;
+    let load_time_ms = start.elapsed().as_millis();
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("Failed to preload model {}: {} {}", model, status, error_text));
+    }
+
+    Ok(PreloadResult { model, load_time_ms })
+}
+
+#[command]
+pub async fn query_ollama_stream(
+    app_handle: AppHandle,
+    model: String,
+    prompt: String,
+    channel: Channel<OllamaResponse>,
+) -> Result<(), String> {
+    let client = reqwest::Client::new();
+    let settings = load_settings(&app_handle);
+
+    let request_body = json!({
+        "model": model,
+        "prompt": prompt,
+        "stream": true
+    });
+
+    log::info!(
+        "Streaming Ollama query with model: {} and prompt length: {}",
+        model,
+        prompt.len()
+    );
+
+    let response = authorize(
+        client.post(&format!("{}/api/generate", settings.api_url)).json(&request_body),
+        &settings,
+    )
+    .send()
+    .await
+    .map_err(|e| format!("Network error: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_default();
+        return Err(format!("Ollama API error {}: {}", status, error_text));
+    }
+
+    // Ollama streams newline-delimited JSON objects; a chunk boundary doesn't
+    // necessarily line up with a line boundary, so buffer across reads.
+    let mut stream = response.bytes_stream();
+    let mut buffer = String::new();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Stream error: {}", e))?;
+        buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+        while let Some(newline_pos) = buffer.find('\n') {
+            let line = buffer[..newline_pos].trim().to_string();
+            buffer.drain(..=newline_pos);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let parsed: OllamaResponse = serde_json::from_str(&line)
+                .map_err(|e| format!("Failed to parse streamed chunk: {}", e))?;
+            let done = parsed.done;
+
+            channel
+                .send(parsed)
+                .map_err(|e| format!("Failed to emit token: {}", e))?;
+
+            if done {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[command]
+pub async fn list_installed_models(app_handle: AppHandle) -> Result<Vec<String>, String> {
+    let client = reqwest::Client::new();
+    let settings = load_settings(&app_handle);
+
+    let request = authorize(client.get(&format!("{}/api/tags", settings.api_url)), &settings);
+
+    match timeout(Duration::from_secs(10), request.send()).await {
         Ok(Ok(response)) => {
             if response.status().is_success() {
                 match response.json::<serde_json::Value>().await {
@@ -325,4 +981,31 @@ pub async fn setup_bundled_ollama(app_handle: AppHandle) -> Result<String, Strin
     }
 
     Ok("Bundled Ollama is ready".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rocm_vram_bytes_from_real_shaped_output() {
+        let sample = br#"{
+            "card0": {
+                "VRAM Total Memory (B)": "17179869184",
+                "VRAM Total Used Memory (B)": "123456789"
+            }
+        }"#;
+
+        let bytes = parse_rocm_vram_bytes(sample).expect("should find a VRAM total");
+        assert_eq!(bytes, 17179869184.0);
+
+        let gb = bytes / (1024.0 * 1024.0 * 1024.0);
+        assert!((gb - 16.0).abs() < 0.01, "expected ~16 GB, got {gb}");
+    }
+
+    #[test]
+    fn returns_none_on_malformed_output() {
+        assert!(parse_rocm_vram_bytes(b"not json").is_none());
+        assert!(parse_rocm_vram_bytes(br#"{"card0": {}}"#).is_none());
+    }
 }
\ No newline at end of file
@@ -1,12 +1,25 @@
 use tauri::command;
 use tauri::Emitter;
+use futures_util::StreamExt;
 use std::process::Command;
 use std::path::{Path, PathBuf};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io;
 use reqwest;
 use zip;
+use flate2::read::GzDecoder;
+use tar::Archive;
+use zstd::stream::read::Decoder as ZstdDecoder;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use toml;
+
+// Default version `setup_embedded_python` bootstraps when the caller
+// doesn't request a specific one.
+const PYTHON_VERSION: &str = "3.11.7";
+// The baseline glibc python-build-standalone's default Linux builds target.
+const MIN_SUPPORTED_GLIBC_MINOR: u32 = 17;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PythonStatus {
@@ -16,6 +29,15 @@ pub struct PythonStatus {
     source: String, // \"bundled\", \"system\", \"none\"
     medical_libraries_available: bool,
     setup_required: bool,
+    managed_versions: Vec<ManagedPythonVersion>,
+}
+
+/// One managed CPython interpreter living under `<app dir>/python/<version>/`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManagedPythonVersion {
+    version: String,
+    python_path: String,
+    active: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,12 +52,33 @@ pub struct PythonSetupProgress {
 #[command]
 pub async fn check_python_status() -> Result<PythonStatus, String> {
     println!("Checking Python status...");
-    
-    // 1. Check for bundled Python first
+
+    let managed_versions = collect_managed_pythons();
+
+    // 1. Check for the active managed (versioned) interpreter first.
+    if let Some((version_dir, version)) = check_managed_python() {
+        let python_exe = python_executable(&version_dir);
+        let python_path = python_exe.to_string_lossy().to_string();
+        let reported_version = get_python_version(&python_path).unwrap_or(version);
+        let medical_libs = medical_libraries_healthy(&python_path, Some(&version_dir)).await;
+
+        return Ok(PythonStatus {
+            is_available: true,
+            python_path: Some(python_path),
+            version: Some(reported_version),
+            source: "bundled".to_string(),
+            medical_libraries_available: medical_libs,
+            setup_required: !medical_libs,
+            managed_versions,
+        });
+    }
+
+    // 2. Legacy flat bundled layout predating version management.
     if let Some(bundled_path) = check_bundled_python() {
         let version = get_python_version(&bundled_path)?;
-        let medical_libs = check_medical_libraries(&bundled_path).await;
-        
+        let python_dir = get_app_resources_dir().map(|dir| dir.join("python")).ok();
+        let medical_libs = medical_libraries_healthy(&bundled_path, python_dir.as_deref()).await;
+
         return Ok(PythonStatus {
             is_available: true,
             python_path: Some(bundled_path),
@@ -43,14 +86,15 @@ pub async fn check_python_status() -> Result<PythonStatus, String> {
             source: "bundled".to_string(),
             medical_libraries_available: medical_libs,
             setup_required: !medical_libs,
+            managed_versions,
         });
     }
-    
-    // 2. Check for system Python
+
+    // 3. Check for system Python
     if let Some(system_path) = check_system_python() {
         let version = get_python_version(&system_path)?;
         let medical_libs = check_medical_libraries(&system_path).await;
-        
+
         return Ok(PythonStatus {
             is_available: true,
             python_path: Some(system_path),
@@ -58,10 +102,11 @@ pub async fn check_python_status() -> Result<PythonStatus, String> {
             source: "system".to_string(),
             medical_libraries_available: medical_libs,
             setup_required: false, // We'll use bundled for medical libs
+            managed_versions,
         });
     }
-    
-    // 3. No Python found
+
+    // 4. No Python found
     Ok(PythonStatus {
         is_available: false,
         python_path: None,
@@ -69,13 +114,70 @@ pub async fn check_python_status() -> Result<PythonStatus, String> {
         source: "none".to_string(),
         medical_libraries_available: false,
         setup_required: true,
+        managed_versions,
     })
 }
 
 #[command]
-pub async fn setup_embedded_python(window: tauri::Window) -> Result<PythonStatus, String> {
-    println!("Setting up embedded Python for medical analysis...");
-    
+pub async fn setup_embedded_python(
+    window: tauri::Window,
+    version: Option<String>,
+    use_uv: Option<bool>,
+) -> Result<PythonStatus, String> {
+    let version = version.unwrap_or_else(|| PYTHON_VERSION.to_string());
+    let status = install_python_version(&window, &version, use_uv).await?;
+
+    // The first/default setup flow also becomes the active interpreter;
+    // fetching additional versions afterward goes through `install_python`
+    // and requires an explicit `select_python` to switch.
+    write_active_version(&managed_python_root()?, &version)?;
+
+    Ok(status)
+}
+
+/// Fetches a specific CPython version into the managed versions directory
+/// without changing which one is active. Use `select_python` afterward to
+/// switch to it.
+#[command]
+pub async fn install_python(
+    window: tauri::Window,
+    version: String,
+    use_uv: Option<bool>,
+) -> Result<PythonStatus, String> {
+    install_python_version(&window, &version, use_uv).await
+}
+
+/// Marks an already-installed managed version as the active interpreter.
+#[command]
+pub async fn select_python(version: String) -> Result<(), String> {
+    let python_root = managed_python_root()?;
+    let version_dir = python_root.join(&version);
+
+    if !python_executable(&version_dir).exists() {
+        return Err(format!("Python {} is not installed", version));
+    }
+
+    write_active_version(&python_root, &version)
+}
+
+/// Lists every managed CPython interpreter under `<app dir>/python/`, along
+/// with which one is currently active.
+#[command]
+pub async fn list_installed_pythons() -> Result<Vec<ManagedPythonVersion>, String> {
+    Ok(collect_managed_pythons())
+}
+
+/// Downloads, extracts, and provisions one managed CPython interpreter into
+/// `<app dir>/python/<version>/`. Shared by `setup_embedded_python` (which
+/// also selects the result as active) and `install_python` (which only adds
+/// it to the managed set).
+async fn install_python_version(
+    window: &tauri::Window,
+    version: &str,
+    use_uv: Option<bool>,
+) -> Result<PythonStatus, String> {
+    println!("Setting up embedded Python {} for medical analysis...", version);
+
     // Send progress updates to frontend
     let send_progress = |step: &str, progress: u8, message: &str| {
         let _ = window.emit("python_setup_progress", PythonSetupProgress {
@@ -86,65 +188,116 @@ pub async fn setup_embedded_python(window: tauri::Window) -> Result<PythonStatus
             error: None,
         });
     };
-    
+
     send_progress("initializing", 0, "Initializing Python setup...");
-    
-    // Create resources directory
-    let app_dir = get_app_resources_dir()?;
-    let python_dir = app_dir.join("python");
-    
+
+    // Create the versioned install directory
+    let python_dir = managed_python_root()?.join(version);
+
     if !python_dir.exists() {
         fs::create_dir_all(&python_dir)
             .map_err(|e| format!("Failed to create Python directory: {}", e))?;
     }
-    
-    send_progress("downloading", 10, "Downloading Python 3.11.7 embedded...");
-    
-    // Download embedded Python
-    let python_url = "https://www.python.org/ftp/python/3.11.7/python-3.11.7-embed-amd64.zip";
-    let zip_path = python_dir.join("python-embed.zip");
-    
-    download_file(python_url, &zip_path).await
+
+    if cfg!(target_os = "windows") {
+        send_progress("downloading", 10, &format!("Downloading Python {} embedded...", version));
+
+        let python_url = format!(
+            "https://www.python.org/ftp/python/{version}/python-{version}-embed-amd64.zip",
+            version = version,
+        );
+        let zip_path = python_dir.join("python-embed.zip");
+
+        // python.org doesn't publish a checksum sidecar for the embeddable
+        // zip, so this download goes unverified.
+        download_file(&python_url, &zip_path, Some(window), "downloading", None)
+            .await
+            .map_err(|e| format!("Failed to download Python: {}", e))?;
+
+        send_progress("extracting", 30, "Extracting Python runtime...");
+
+        extract_zip(&zip_path, &python_dir)
+            .map_err(|e| format!("Failed to extract Python: {}", e))?;
+
+        let _ = fs::remove_file(&zip_path);
+    } else {
+        let triple = target_triple()?;
+        send_progress("downloading", 10, &format!("Downloading Python {} for {}...", version, triple));
+
+        let python_url = python_download_url(&triple, version)?;
+        let archive_path = python_dir.join("python-standalone.tar.gz");
+        let expected_sha256 = resolve_expected_sha256(&python_url).await;
+
+        download_file(
+            &python_url,
+            &archive_path,
+            Some(window),
+            "downloading",
+            expected_sha256.as_deref(),
+        )
+        .await
         .map_err(|e| format!("Failed to download Python: {}", e))?;
-    
-    send_progress("extracting", 30, "Extracting Python runtime...");
-    
-    // Extract Python
-    extract_zip(&zip_path, &python_dir)
-        .map_err(|e| format!("Failed to extract Python: {}", e))?;
-    
-    // Remove zip file
-    let _ = fs::remove_file(&zip_path);
-    
+
+        send_progress("extracting", 30, "Extracting Python runtime...");
+
+        extract_tar_archive(&archive_path, &python_dir)
+            .map_err(|e| format!("Failed to extract Python: {}", e))?;
+
+        let _ = fs::remove_file(&archive_path);
+    }
+
     send_progress("configuring", 50, "Configuring Python environment...");
-    
+
     // Configure Python for pip
     configure_embedded_python(&python_dir)?;
-    
+
     send_progress("installing_pip", 60, "Installing package manager...");
-    
+
     // Install pip
-    install_pip(&python_dir).await?;
-    
+    install_pip(&python_dir, window).await?;
+
     send_progress("installing_libraries", 70, "Installing medical analysis libraries...");
-    
-    // Install medical libraries
-    install_medical_libraries(&python_dir).await?;
-    
+
+    // Install medical libraries. A lockfile from a prior run makes this
+    // reproducible: install strictly from it instead of re-resolving the
+    // manifest. Otherwise resolve the manifest once (preferring the faster
+    // uv backend, falling back to pip) and freeze the result into a lock.
+    let manifest = load_or_init_manifest(&python_dir)?;
+
+    match load_lockfile(&python_dir) {
+        Some(lock) if !lock.packages.is_empty() => install_from_lockfile(&python_dir, &lock).await?,
+        _ => {
+            if use_uv.unwrap_or(true) {
+                match install_uv(&python_dir, window).await {
+                    Ok(uv_path) => install_medical_libraries_uv(&python_dir, &uv_path, &manifest, window).await?,
+                    Err(e) => {
+                        println!("Falling back to pip: failed to set up uv ({})", e);
+                        install_medical_libraries(&python_dir, &manifest, window).await?;
+                    }
+                }
+            } else {
+                install_medical_libraries(&python_dir, &manifest, window).await?;
+            }
+
+            let lock = freeze_to_lockfile(&python_dir).await?;
+            write_lockfile(&python_dir, &lock)?;
+        }
+    }
+
     send_progress("verifying", 90, "Verifying installation...");
-    
+
     // Verify installation
-    let python_exe = python_dir.join("python.exe");
+    let python_exe = python_executable(&python_dir);
     let python_path = python_exe.to_string_lossy().to_string();
-    let version = get_python_version(&python_path)?;
-    let medical_libs = check_medical_libraries(&python_path).await;
-    
+    let reported_version = get_python_version(&python_path)?;
+    let medical_libs = medical_libraries_healthy(&python_path, Some(&python_dir)).await;
+
     if !medical_libs {
         return Err("Medical libraries verification failed".to_string());
     }
-    
+
     send_progress("completed", 100, "Python setup completed successfully!");
-    
+
     // Send completion signal
     let _ = window.emit("python_setup_progress", PythonSetupProgress {
         step: "completed".to_string(),
@@ -153,14 +306,15 @@ pub async fn setup_embedded_python(window: tauri::Window) -> Result<PythonStatus
         completed: true,
         error: None,
     });
-    
+
     Ok(PythonStatus {
         is_available: true,
         python_path: Some(python_path),
-        version: Some(version),
+        version: Some(reported_version),
         source: "bundled".to_string(),
         medical_libraries_available: true,
         setup_required: false,
+        managed_versions: collect_managed_pythons(),
     })
 }
 
@@ -177,34 +331,215 @@ pub async fn get_python_path() -> Result<String, String> {
 
 // Helper functions
 
+/// Path to the interpreter inside a Python install directory. Unix standalone
+/// builds extract to a nested `python/` tree; the Windows embeddable zip
+/// extracts flat.
+fn python_executable(python_dir: &Path) -> PathBuf {
+    if cfg!(target_os = "windows") {
+        python_dir.join("python.exe")
+    } else {
+        python_dir.join("python").join("bin").join("python3")
+    }
+}
+
+enum LinuxLibc {
+    Gnu { minor: u32 },
+    Musl,
+}
+
+impl LinuxLibc {
+    fn variant(&self) -> &'static str {
+        match self {
+            LinuxLibc::Gnu { .. } => "gnu",
+            LinuxLibc::Musl => "musl",
+        }
+    }
+}
+
+/// Probes `ldd --version` to tell glibc and musl hosts apart, and to reject
+/// a glibc baseline the host's libc is too old to run.
+fn detect_linux_libc() -> Result<LinuxLibc, String> {
+    let output = Command::new("ldd")
+        .arg("--version")
+        .output()
+        .map_err(|e| format!("Failed to probe libc via ldd: {}", e))?;
+
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    if text.to_lowercase().contains("musl") {
+        return Ok(LinuxLibc::Musl);
+    }
+
+    // Typical glibc first line: "ldd (GNU libc) 2.35"
+    let minor = text
+        .lines()
+        .next()
+        .and_then(|line| line.rsplit('.').next())
+        .and_then(|s| s.trim().parse::<u32>().ok())
+        .ok_or_else(|| "Failed to parse glibc version from ldd output".to_string())?;
+
+    if minor < MIN_SUPPORTED_GLIBC_MINOR {
+        return Err(format!(
+            "Host glibc 2.{} is older than the 2.{} baseline the bundled Python build requires",
+            minor, MIN_SUPPORTED_GLIBC_MINOR
+        ));
+    }
+
+    Ok(LinuxLibc::Gnu { minor })
+}
+
+/// Resolves the python-build-standalone target triple for the host, e.g.
+/// `x86_64-unknown-linux-gnu` or `aarch64-apple-darwin`.
+fn target_triple() -> Result<String, String> {
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => "x86_64",
+        "aarch64" => "aarch64",
+        other => return Err(format!("Unsupported CPU architecture: {}", other)),
+    };
+
+    let triple = match std::env::consts::OS {
+        "linux" => format!("{}-unknown-linux-{}", arch, detect_linux_libc()?.variant()),
+        "macos" => format!("{}-apple-darwin", arch),
+        "windows" => format!("{}-pc-windows-msvc", arch),
+        other => return Err(format!("Unsupported OS for standalone Python: {}", other)),
+    };
+
+    Ok(triple)
+}
+
+/// python-build-standalone release tags for the CPython versions we know
+/// how to fetch. Add an entry here before offering a new version through
+/// `install_python`/`setup_embedded_python`.
+fn python_build_release_for(version: &str) -> Result<&'static str, String> {
+    match version {
+        "3.10.13" => Ok("20240107"),
+        "3.11.7" => Ok("20240107"),
+        "3.12.1" => Ok("20240107"),
+        other => Err(format!("No known python-build-standalone release for Python {}", other)),
+    }
+}
+
+fn python_download_url(triple: &str, version: &str) -> Result<String, String> {
+    let release = python_build_release_for(version)?;
+    Ok(format!(
+        "https://github.com/indygreg/python-build-standalone/releases/download/{release}/cpython-{version}+{release}-{triple}-install_only.tar.gz",
+        release = release,
+        version = version,
+        triple = triple,
+    ))
+}
+
+/// Root directory holding every managed interpreter, one subfolder per
+/// version (`python/3.11.7/`, `python/3.12.1/`, ...).
+fn managed_python_root() -> Result<PathBuf, String> {
+    Ok(get_app_resources_dir()?.join("python"))
+}
+
+fn active_version_marker(python_root: &Path) -> PathBuf {
+    python_root.join("active_version")
+}
+
+fn read_active_version(python_root: &Path) -> Option<String> {
+    fs::read_to_string(active_version_marker(python_root))
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn write_active_version(python_root: &Path, version: &str) -> Result<(), String> {
+    fs::create_dir_all(python_root).map_err(|e| format!("Failed to create python directory: {}", e))?;
+    fs::write(active_version_marker(python_root), version)
+        .map_err(|e| format!("Failed to record active Python version: {}", e))
+}
+
+/// Falls back to whichever managed version was installed most recently when
+/// none has been explicitly selected yet.
+fn most_recent_managed_version(python_root: &Path) -> Option<String> {
+    fs::read_dir(python_root)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.file_name().to_string_lossy().to_string()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, version)| version)
+}
+
+/// Resolves the active managed interpreter: the one `select_python` chose,
+/// or the most recently installed one if nothing was chosen yet.
+fn check_managed_python() -> Option<(PathBuf, String)> {
+    let python_root = managed_python_root().ok()?;
+    let version = read_active_version(&python_root).or_else(|| most_recent_managed_version(&python_root))?;
+
+    let version_dir = python_root.join(&version);
+    python_executable(&version_dir).exists().then_some((version_dir, version))
+}
+
+fn collect_managed_pythons() -> Vec<ManagedPythonVersion> {
+    let Ok(python_root) = managed_python_root() else { return Vec::new() };
+    let Ok(entries) = fs::read_dir(&python_root) else { return Vec::new() };
+
+    let active = read_active_version(&python_root).or_else(|| most_recent_managed_version(&python_root));
+
+    let mut versions: Vec<ManagedPythonVersion> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let version = entry.file_name().to_string_lossy().to_string();
+            let exe = python_executable(&entry.path());
+            exe.exists().then(|| ManagedPythonVersion {
+                active: active.as_deref() == Some(version.as_str()),
+                python_path: exe.to_string_lossy().to_string(),
+                version,
+            })
+        })
+        .collect();
+
+    versions.sort_by(|a, b| a.version.cmp(&b.version));
+    versions
+}
+
 fn check_bundled_python() -> Option<String> {
-    let possible_paths = vec![
-        "resources/python/python.exe",
-        "python/python.exe",
-    ];
-    
-    for path_str in possible_paths {
-        let path = Path::new(path_str);
+    let possible_paths: Vec<PathBuf> = if cfg!(target_os = "windows") {
+        vec![
+            PathBuf::from("resources/python/python.exe"),
+            PathBuf::from("python/python.exe"),
+        ]
+    } else {
+        vec![
+            python_executable(Path::new("resources/python")),
+            python_executable(Path::new("python")),
+        ]
+    };
+
+    for path in possible_paths {
         if path.exists() {
             return Some(path.to_string_lossy().to_string());
         }
     }
-    
+
     // Check in app resources directory
     if let Ok(app_dir) = get_app_resources_dir() {
-        let python_exe = app_dir.join("python").join("python.exe");
+        let python_exe = python_executable(&app_dir.join("python"));
         if python_exe.exists() {
             return Some(python_exe.to_string_lossy().to_string());
         }
     }
-    
+
     None
 }
 
 fn check_system_python() -> Option<String> {
     // Try common Python commands
-    let commands = vec!["python", "python3", "py"];
-    
+    let commands = vec!["python3", "python", "py"];
+    let locator = if cfg!(target_os = "windows") { "where" } else { "which" };
+
     for cmd in commands {
         if let Ok(output) = Command::new(cmd)
             .arg("--version")
@@ -212,7 +547,7 @@ fn check_system_python() -> Option<String> {
         {
             if output.status.success() {
                 // Get full path
-                if let Ok(path_output) = Command::new("where")
+                if let Ok(path_output) = Command::new(locator)
                     .arg(cmd)
                     .output()
                 {
@@ -226,7 +561,7 @@ fn check_system_python() -> Option<String> {
             }
         }
     }
-    
+
     None
 }
 
@@ -281,6 +616,58 @@ print(json.dumps(available))
     false
 }
 
+/// Checks installed libraries against the manifest/lockfile when a lockfile
+/// exists for this interpreter, falling back to a plain importability check
+/// (e.g. for a system Python, which we don't manage a lockfile for).
+async fn medical_libraries_healthy(python_path: &str, python_dir: Option<&Path>) -> bool {
+    match python_dir.and_then(|dir| Some((load_lockfile(dir)?, dir))) {
+        Some((lock, dir)) if !lock.packages.is_empty() => {
+            let manifest = load_or_init_manifest(dir).unwrap_or_else(|_| default_manifest());
+            validate_against_lock(python_path, &manifest, &lock)
+        }
+        _ => check_medical_libraries(python_path).await,
+    }
+}
+
+/// A lock that merely matches itself proves nothing: `freeze_to_lockfile`
+/// only ever records whatever `pip freeze` reports, so if a required library
+/// silently failed to install it simply won't be in `lock.packages`, and
+/// checking the lock against itself is vacuously true. We therefore check the
+/// *manifest's* required dependencies are present in the lock first, then
+/// confirm the interpreter's actual installed versions still match it.
+fn validate_against_lock(python_path: &str, manifest: &MedicalEnvManifest, lock: &MedicalEnvLock) -> bool {
+    let locked_names: std::collections::HashSet<String> =
+        lock.packages.keys().map(|name| name.to_lowercase()).collect();
+    let has_all_required = manifest
+        .dependencies
+        .keys()
+        .all(|name| locked_names.contains(&name.to_lowercase()));
+    if !has_all_required {
+        return false;
+    }
+
+    let Ok(output) = Command::new(python_path).args(&["-m", "pip", "freeze"]).output() else {
+        return false;
+    };
+    if !output.status.success() {
+        return false;
+    }
+
+    let freeze_text = String::from_utf8_lossy(&output.stdout);
+    let installed: std::collections::HashMap<String, String> = freeze_text
+        .lines()
+        .filter_map(|line| line.split_once("=="))
+        .map(|(name, version)| (name.to_lowercase(), version.to_string()))
+        .collect();
+
+    lock.packages.iter().all(|(name, locked)| {
+        installed
+            .get(&name.to_lowercase())
+            .map(|version| version == &locked.version)
+            .unwrap_or(false)
+    })
+}
+
 fn get_app_resources_dir() -> Result<PathBuf, String> {
     let exe_path = std::env::current_exe()
         .map_err(|e| format!("Failed to get executable path: {}", e))?;
@@ -291,12 +678,140 @@ fn get_app_resources_dir() -> Result<PathBuf, String> {
     Ok(app_dir.to_path_buf())
 }
 
-async fn download_file(url: &str, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Lookup for SHA256 digests pinned in this binary, keyed by filename (the
+/// last path segment of the download URL), which is unique per platform
+/// variant. A digest baked into this binary protects against a compromised
+/// release or an active MITM tampering with the download: both would have to
+/// also compromise whatever shipped this binary, not just the single download
+/// connection `fetch_sha256_sidecar` trusts.
+///
+/// No digests are registered: this returns `None` for every filename, so
+/// every download falls back to the `.sha256` sidecar in
+/// [`resolve_expected_sha256`], or goes unverified when that is unavailable.
+fn known_sha256(_filename: &str) -> Option<&'static str> {
+    None
+}
+
+/// Fetches the `<asset>.sha256` sidecar file both python-build-standalone
+/// and uv (via cargo-dist) publish alongside every release artifact. This is
+/// weaker than [`known_sha256`] — the sidecar is served from the same host
+/// as the artifact, so it only catches accidental corruption, not a
+/// tampered release or MITM that serves matching bad bytes for both files.
+/// Used only as a fallback when no pinned digest is registered.
+async fn fetch_sha256_sidecar(archive_url: &str) -> Option<String> {
+    let client = reqwest::Client::new();
+    let response = client.get(format!("{}.sha256", archive_url)).send().await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let text = response.text().await.ok()?;
+    text.split_whitespace().next().map(|digest| digest.to_lowercase())
+}
+
+/// Resolves the checksum to verify `url`'s download against: a pinned digest
+/// from [`known_sha256`] when one is registered, otherwise the weaker
+/// same-host sidecar, with a loud warning that verification is degraded so a
+/// blocked/failed sidecar fetch can't silently masquerade as "verified".
+async fn resolve_expected_sha256(url: &str) -> Option<String> {
+    let filename = url.rsplit('/').next().unwrap_or(url);
+
+    if let Some(digest) = known_sha256(filename) {
+        return Some(digest.to_string());
+    }
+
+    log::warn!(
+        "No pinned checksum registered for {}; falling back to its published \
+         .sha256 sidecar, which only guards against accidental corruption, \
+         not a tampered release or an active MITM.",
+        filename
+    );
+
+    match fetch_sha256_sidecar(url).await {
+        Some(digest) => Some(digest),
+        None => {
+            log::warn!("No checksum sidecar available for {} either; downloading unverified.", filename);
+            None
+        }
+    }
+}
+
+/// Streams a download to disk chunk-by-chunk (so multi-gigabyte artifacts
+/// never sit fully in memory), reporting byte progress through
+/// `python_setup_progress`, and verifies the result against
+/// `expected_sha256` when the caller passes one, removing the file on a
+/// mismatch.
+async fn download_file(
+    url: &str,
+    path: &Path,
+    window: Option<&tauri::Window>,
+    step: &str,
+    expected_sha256: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let client = reqwest::Client::new();
     let response = client.get(url).send().await?;
-    let bytes = response.bytes().await?;
-    
-    fs::write(path, bytes)?;
+    let total = response.content_length();
+
+    let mut file = fs::File::create(path)?;
+    let mut stream = response.bytes_stream();
+    let mut hasher = Sha256::new();
+    let mut downloaded: u64 = 0;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        hasher.update(&chunk);
+        io::Write::write_all(&mut file, &chunk)?;
+        downloaded += chunk.len() as u64;
+
+        if let Some(window) = window {
+            let progress = total
+                .map(|total| ((downloaded as f64 / total as f64) * 100.0) as u8)
+                .unwrap_or(0);
+            let message = match total {
+                Some(total) => format!("Downloaded {} of {}", format_bytes(downloaded), format_bytes(total)),
+                None => format!("Downloaded {}", format_bytes(downloaded)),
+            };
+            let _ = window.emit(
+                "python_setup_progress",
+                PythonSetupProgress {
+                    step: step.to_string(),
+                    progress,
+                    message,
+                    completed: false,
+                    error: None,
+                },
+            );
+        }
+    }
+
+    drop(file);
+
+    if let Some(expected) = expected_sha256 {
+        let actual = format!("{:x}", hasher.finalize());
+        if actual != expected {
+            let _ = fs::remove_file(path);
+            return Err(format!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                path.display(),
+                expected,
+                actual
+            )
+            .into());
+        }
+    }
+
     Ok(())
 }
 
@@ -324,56 +839,293 @@ fn extract_zip(zip_path: &Path, extract_to: &Path) -> Result<(), Box<dyn std::er
     Ok(())
 }
 
+fn extract_tar_gz(archive_path: &Path, extract_to: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let file = fs::File::open(archive_path)?;
+    let decoder = GzDecoder::new(file);
+    let mut archive = Archive::new(decoder);
+    archive.unpack(extract_to)?;
+    Ok(())
+}
+
+fn extract_tar_zst(archive_path: &Path, extract_to: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let file = fs::File::open(archive_path)?;
+    let decoder = ZstdDecoder::new(file)?;
+    let mut archive = Archive::new(decoder);
+    archive.unpack(extract_to)?;
+    Ok(())
+}
+
+/// Dispatches to the right decompressor based on the downloaded archive's
+/// extension; python-build-standalone publishes both `.tar.gz` and `.tar.zst`.
+fn extract_tar_archive(archive_path: &Path, extract_to: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let name = archive_path.to_string_lossy();
+    if name.ends_with(".tar.zst") || name.ends_with(".zst") {
+        extract_tar_zst(archive_path, extract_to)
+    } else {
+        extract_tar_gz(archive_path, extract_to)
+    }
+}
+
 fn configure_embedded_python(python_dir: &Path) -> Result<(), String> {
+    // Standalone CPython builds used on macOS/Linux already ship with
+    // site-packages enabled; only the Windows embeddable zip needs patching.
+    if !cfg!(target_os = "windows") {
+        return Ok(());
+    }
+
     // Modify python311._pth to enable site-packages
     let pth_file = python_dir.join("python311._pth");
-    
+
     if pth_file.exists() {
         let mut content = fs::read_to_string(&pth_file)
             .map_err(|e| format!("Failed to read pth file: {}", e))?;
-        
+
         if !content.contains("import site") {
             content.push_str("\nimport site\n");
             fs::write(&pth_file, content)
                 .map_err(|e| format!("Failed to write pth file: {}", e))?;
         }
     }
-    
+
     Ok(())
 }
 
-async fn install_pip(python_dir: &Path) -> Result<(), String> {
-    let python_exe = python_dir.join("python.exe");
-    let get_pip_path = python_dir.join("get-pip.py");
-    
-    // Download get-pip.py
-    download_file("https://bootstrap.pypa.io/get-pip.py", &get_pip_path)
+async fn install_pip(python_dir: &Path, window: &tauri::Window) -> Result<(), String> {
+    let python_exe = python_executable(python_dir);
+
+    if cfg!(target_os = "windows") {
+        let get_pip_path = python_dir.join("get-pip.py");
+
+        // bootstrap.pypa.io serves the latest get-pip.py unversioned and
+        // publishes no checksum sidecar, so this download goes unverified.
+        download_file(
+            "https://bootstrap.pypa.io/get-pip.py",
+            &get_pip_path,
+            Some(window),
+            "installing_pip",
+            None,
+        )
         .await
         .map_err(|e| format!("Failed to download get-pip.py: {}", e))?;
-    
-    // Install pip
+
+        let output = Command::new(&python_exe)
+            .arg(&get_pip_path)
+            .arg("--no-warn-script-location")
+            .output()
+            .map_err(|e| format!("Failed to install pip: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!("Pip installation failed: {}", String::from_utf8_lossy(&output.stderr)));
+        }
+
+        let _ = fs::remove_file(&get_pip_path);
+    } else {
+        // Standalone CPython builds ship ensurepip, so there's no get-pip.py
+        // download needed outside the Windows embeddable zip.
+        let output = Command::new(&python_exe)
+            .args(&["-m", "ensurepip", "--upgrade"])
+            .output()
+            .map_err(|e| format!("Failed to install pip: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!("Pip installation failed: {}", String::from_utf8_lossy(&output.stderr)));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MedicalEnvManifest {
+    dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    optional_dependencies: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LockedPackage {
+    version: String,
+    sha256: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MedicalEnvLock {
+    packages: BTreeMap<String, LockedPackage>,
+}
+
+fn manifest_path(python_dir: &Path) -> PathBuf {
+    python_dir.join("medical-env.toml")
+}
+
+fn lockfile_path(python_dir: &Path) -> PathBuf {
+    python_dir.join("medical-env.lock")
+}
+
+/// Seeds `medical-env.toml` from the pinned library list the first time it's
+/// needed, so the manifest is the single source of truth afterward.
+fn default_manifest() -> MedicalEnvManifest {
+    let (required, optional) = medical_library_specs();
+
+    let dependencies = required
+        .iter()
+        .filter_map(|spec| spec.split_once("=="))
+        .map(|(name, version)| (name.to_string(), version.to_string()))
+        .collect();
+
+    let optional_dependencies = optional.iter().map(|name| (name.to_string(), "*".to_string())).collect();
+
+    MedicalEnvManifest { dependencies, optional_dependencies }
+}
+
+fn load_or_init_manifest(python_dir: &Path) -> Result<MedicalEnvManifest, String> {
+    let path = manifest_path(python_dir);
+
+    if let Ok(contents) = fs::read_to_string(&path) {
+        return toml::from_str(&contents).map_err(|e| format!("Failed to parse medical-env.toml: {}", e));
+    }
+
+    let manifest = default_manifest();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create python directory: {}", e))?;
+    }
+    let contents = toml::to_string_pretty(&manifest).map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    fs::write(&path, contents).map_err(|e| format!("Failed to write medical-env.toml: {}", e))?;
+
+    Ok(manifest)
+}
+
+fn load_lockfile(python_dir: &Path) -> Option<MedicalEnvLock> {
+    let contents = fs::read_to_string(lockfile_path(python_dir)).ok()?;
+    toml::from_str(&contents).ok()
+}
+
+fn write_lockfile(python_dir: &Path, lock: &MedicalEnvLock) -> Result<(), String> {
+    let contents = toml::to_string_pretty(lock).map_err(|e| format!("Failed to serialize lockfile: {}", e))?;
+    fs::write(lockfile_path(python_dir), contents).map_err(|e| format!("Failed to write medical-env.lock: {}", e))
+}
+
+fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher).map_err(|e| format!("Failed to hash {:?}: {}", path, e))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Installs strictly from the lockfile's pinned name==version pairs. Only the
+/// versions are pinned: the `sha256` recorded for each package is not passed
+/// to pip, so the installed files are not checked against it.
+async fn install_from_lockfile(python_dir: &Path, lock: &MedicalEnvLock) -> Result<(), String> {
+    let python_exe = python_executable(python_dir);
+    let specs: Vec<String> = lock
+        .packages
+        .iter()
+        .map(|(name, pkg)| format!("{}=={}", name, pkg.version))
+        .collect();
+
+    let mut args = vec!["-m".to_string(), "pip".to_string(), "install".to_string(), "--quiet".to_string(), "--disable-pip-version-check".to_string()];
+    args.extend(specs);
+
     let output = Command::new(&python_exe)
-        .arg(&get_pip_path)
-        .arg("--no-warn-script-location")
+        .args(&args)
         .output()
-        .map_err(|e| format!("Failed to install pip: {}", e))?;
-    
+        .map_err(|e| format!("Failed to install from lockfile: {}", e))?;
+
     if !output.status.success() {
-        return Err(format!("Pip installation failed: {}", String::from_utf8_lossy(&output.stderr)));
+        return Err(format!(
+            "Failed to install locked dependencies: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
     }
-    
-    // Remove get-pip.py
-    let _ = fs::remove_file(&get_pip_path);
-    
+
     Ok(())
 }
 
-async fn install_medical_libraries(python_dir: &Path) -> Result<(), String> {
-    let python_exe = python_dir.join("python.exe");
-    
-    let libraries = vec![
+/// Freezes the environment the caller has already installed (via uv or pip)
+/// — every transitive dependency included — into a lockfile, recording for
+/// each package the SHA256 of the distribution `pip download` fetches for
+/// it. The hash is left empty when that download fails.
+async fn freeze_to_lockfile(python_dir: &Path) -> Result<MedicalEnvLock, String> {
+    let python_exe = python_executable(python_dir);
+
+    let output = Command::new(&python_exe)
+        .args(&["-m", "pip", "freeze"])
+        .output()
+        .map_err(|e| format!("Failed to freeze installed packages: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("pip freeze failed: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let freeze_text = String::from_utf8_lossy(&output.stdout);
+    let cache_dir = python_dir.join("lock-cache");
+    fs::create_dir_all(&cache_dir).map_err(|e| format!("Failed to create lock cache directory: {}", e))?;
+
+    let mut packages = BTreeMap::new();
+
+    for line in freeze_text.lines() {
+        let Some((name, version)) = line.split_once("==") else { continue };
+
+        let downloaded = Command::new(&python_exe)
+            .args(&["-m", "pip", "download", "--no-deps", "--quiet", "--dest"])
+            .arg(&cache_dir)
+            .arg(format!("{}=={}", name, version))
+            .output();
+
+        let sha256 = downloaded
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|_| fs::read_dir(&cache_dir).ok())
+            .and_then(|mut entries| entries.find_map(|entry| entry.ok()))
+            .and_then(|entry| sha256_file(&entry.path()).ok())
+            .unwrap_or_default();
+
+        packages.insert(name.to_string(), LockedPackage { version: version.to_string(), sha256 });
+
+        let _ = fs::remove_dir_all(&cache_dir);
+        let _ = fs::create_dir_all(&cache_dir);
+    }
+
+    let _ = fs::remove_dir_all(&cache_dir);
+
+    Ok(MedicalEnvLock { packages })
+}
+
+#[command]
+pub async fn regenerate_medical_lockfile() -> Result<(), String> {
+    let (python_dir, _version) =
+        check_managed_python().ok_or("No managed Python interpreter is installed")?;
+
+    let manifest = load_or_init_manifest(&python_dir)?;
+    let mut specs: Vec<String> = manifest
+        .dependencies
+        .iter()
+        .map(|(name, version)| format!("{}=={}", name, version))
+        .collect();
+    specs.extend(manifest.optional_dependencies.keys().cloned());
+
+    let python_exe = python_executable(&python_dir);
+    let mut args = vec!["-m".to_string(), "pip".to_string(), "install".to_string(), "--quiet".to_string(), "--disable-pip-version-check".to_string(), "--upgrade".to_string()];
+    args.extend(specs);
+
+    let output = Command::new(&python_exe)
+        .args(&args)
+        .output()
+        .map_err(|e| format!("Failed to resolve manifest: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("Failed to resolve manifest: {}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let lock = freeze_to_lockfile(&python_dir).await?;
+    write_lockfile(&python_dir, &lock)
+}
+
+/// Required and optional (best-effort) medical library pins used to seed
+/// `medical-env.toml` the first time it's created. Once it exists, the
+/// manifest — not this list — drives what actually gets installed.
+fn medical_library_specs() -> (Vec<&'static str>, Vec<&'static str>) {
+    let required = vec![
         "pandas==2.1.4",
-        "numpy==1.24.4", 
+        "numpy==1.24.4",
         "scipy==1.11.4",
         "matplotlib==3.8.2",
         "seaborn==0.13.0",
@@ -381,27 +1133,183 @@ async fn install_medical_libraries(python_dir: &Path) -> Result<(), String> {
         "scikit-learn==1.3.2",
         "plotly==5.18.0",
     ];
-    
-    for lib in libraries {
+    let optional = vec!["pingouin", "lifelines"];
+
+    (required, optional)
+}
+
+/// Builds pip/uv install specs from a resolved manifest: pinned
+/// `name==version` for required dependencies, bare names (best-effort, no
+/// pin) for optional ones.
+fn manifest_specs(manifest: &MedicalEnvManifest) -> (Vec<String>, Vec<String>) {
+    let required = manifest
+        .dependencies
+        .iter()
+        .map(|(name, version)| format!("{}=={}", name, version))
+        .collect();
+    let optional = manifest.optional_dependencies.keys().cloned().collect();
+
+    (required, optional)
+}
+
+async fn install_medical_libraries(
+    python_dir: &Path,
+    manifest: &MedicalEnvManifest,
+    window: &tauri::Window,
+) -> Result<(), String> {
+    let python_exe = python_executable(python_dir);
+    let (required, optional) = manifest_specs(manifest);
+
+    for lib in required {
         let output = Command::new(&python_exe)
-            .args(&["-m", "pip", "install", lib, "--quiet", "--disable-pip-version-check"])
+            .args(&["-m", "pip", "install", &lib, "--quiet", "--disable-pip-version-check"])
             .output()
             .map_err(|e| format!("Failed to install {}: {}", lib, e))?;
-        
+
         if !output.status.success() {
-            println!("Warning: Failed to install {}: {}", lib, String::from_utf8_lossy(&output.stderr));
+            let error = String::from_utf8_lossy(&output.stderr).to_string();
+            println!("Warning: Failed to install {}: {}", lib, error);
+            let _ = window.emit(
+                "python_setup_progress",
+                PythonSetupProgress {
+                    step: "installing_libraries".to_string(),
+                    progress: 70,
+                    message: format!("Failed to install {}", lib),
+                    completed: false,
+                    error: Some(error),
+                },
+            );
             // Continue with other libraries
         }
     }
-    
+
     // Install optional medical libraries (don't fail if these don't work)
-    let optional_libs = vec!["pingouin", "lifelines"];
-    
-    for lib in optional_libs {
+    for lib in optional {
         let _ = Command::new(&python_exe)
-            .args(&["-m", "pip", "install", lib, "--quiet", "--disable-pip-version-check"])
+            .args(&["-m", "pip", "install", &lib, "--quiet", "--disable-pip-version-check"])
             .output();
     }
-    
+
+    Ok(())
+}
+
+const UV_VERSION: &str = "0.5.11";
+
+fn uv_binary_path(python_dir: &Path) -> PathBuf {
+    python_dir.join(if cfg!(target_os = "windows") { "uv.exe" } else { "uv" })
+}
+
+fn uv_download_url(triple: &str) -> String {
+    let ext = if cfg!(target_os = "windows") { "zip" } else { "tar.gz" };
+    format!(
+        "https://github.com/astral-sh/uv/releases/download/{version}/uv-{triple}.{ext}",
+        version = UV_VERSION,
+        triple = triple,
+        ext = ext,
+    )
+}
+
+/// Downloads the standalone `uv` binary into the python dir. Returns its path
+/// on success so the caller can fall back to the pip backend on any failure.
+async fn install_uv(python_dir: &Path, window: &tauri::Window) -> Result<PathBuf, String> {
+    let triple = target_triple()?;
+    let url = uv_download_url(&triple);
+    let archive_path = python_dir.join(if cfg!(target_os = "windows") { "uv.zip" } else { "uv.tar.gz" });
+    let expected_sha256 = resolve_expected_sha256(&url).await;
+
+    download_file(
+        &url,
+        &archive_path,
+        Some(window),
+        "installing_libraries",
+        expected_sha256.as_deref(),
+    )
+    .await
+    .map_err(|e| format!("Failed to download uv: {}", e))?;
+
+    let extract_result = if cfg!(target_os = "windows") {
+        extract_zip(&archive_path, python_dir)
+    } else {
+        extract_tar_archive(&archive_path, python_dir)
+    };
+    extract_result.map_err(|e| format!("Failed to extract uv: {}", e))?;
+    let _ = fs::remove_file(&archive_path);
+
+    let uv_path = uv_binary_path(python_dir);
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = fs::metadata(&uv_path) {
+            let mut perms = metadata.permissions();
+            perms.set_mode(0o755);
+            let _ = fs::set_permissions(&uv_path, perms);
+        }
+    }
+
+    if !uv_path.exists() {
+        return Err("uv binary not found after extraction".to_string());
+    }
+
+    Ok(uv_path)
+}
+
+/// Resolves and installs the whole medical library set with a single `uv pip
+/// install` call. If the batch install fails, retries package-by-package so
+/// the frontend learns exactly which library broke instead of a single
+/// opaque resolver error.
+async fn install_medical_libraries_uv(
+    python_dir: &Path,
+    uv_path: &Path,
+    manifest: &MedicalEnvManifest,
+    window: &tauri::Window,
+) -> Result<(), String> {
+    let python_exe = python_executable(python_dir);
+    let python_exe = python_exe.to_string_lossy().to_string();
+    let (required, optional) = manifest_specs(manifest);
+    let all_libs: Vec<&str> = required.iter().chain(optional.iter()).map(|s| s.as_str()).collect();
+
+    let mut args = vec!["pip", "install", "--python", &python_exe];
+    args.extend(all_libs.iter().copied());
+
+    let output = Command::new(uv_path)
+        .args(&args)
+        .output()
+        .map_err(|e| format!("Failed to run uv: {}", e))?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    println!(
+        "uv batch install failed, retrying per-package to attribute the failure: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    for lib in all_libs {
+        let result = Command::new(uv_path)
+            .args(&["pip", "install", "--python", &python_exe, lib])
+            .output();
+
+        let error = match result {
+            Ok(output) if output.status.success() => None,
+            Ok(output) => Some(String::from_utf8_lossy(&output.stderr).to_string()),
+            Err(e) => Some(e.to_string()),
+        };
+
+        if let Some(error) = error {
+            let _ = window.emit(
+                "python_setup_progress",
+                PythonSetupProgress {
+                    step: "installing_libraries".to_string(),
+                    progress: 70,
+                    message: format!("Failed to install {}", lib),
+                    completed: false,
+                    error: Some(error),
+                },
+            );
+        }
+    }
+
     Ok(())
 }